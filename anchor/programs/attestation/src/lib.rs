@@ -38,12 +38,15 @@ pub const CONFIG_SEED: &[u8] = b"config";
 pub const ATTESTATION_SEED: &[u8] = b"attestation";
 pub const TREE_AUTHORITY_SEED: &[u8] = b"tree_authority";
 pub const CERTIFICATE_SEED: &[u8] = b"certificate";
+pub const EMITTER_SEED: &[u8] = b"emitter";
 
 pub const MAX_CONTENT_TYPE_LEN: usize = 20;
 pub const MAX_DETECTION_MODEL_LEN: usize = 32;
 pub const MAX_METADATA_URI_LEN: usize = 200;
 pub const MAX_NAME_LEN: usize = 32;
 pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_CREATOR_LIMIT: usize = 5;
+pub const MAX_VERIFIERS: usize = 32;
 
 // Bubblegum Program ID (Metaplex) - hardcoded for reference
 // In production, pass these as accounts
@@ -69,6 +72,16 @@ pub mod external_programs {
         use super::*;
         anchor_lang::declare_id!("H5sFv8VwWmjxHYS2GB4fTDsK7uTtnRT4WiixtHrET3bN");
     }
+
+    pub mod wormhole {
+        use super::*;
+        anchor_lang::declare_id!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+    }
+
+    pub mod token_metadata {
+        use super::*;
+        anchor_lang::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+    }
 }
 
 // ============================================================
@@ -89,7 +102,10 @@ pub mod attestation {
         config.merkle_tree = Pubkey::default();
         config.tree_authority_bump = ctx.bumps.config;
         config.bump = ctx.bumps.config;
-        
+        config.verifiers = [Pubkey::default(); MAX_VERIFIERS];
+        config.verifier_count = 0;
+        config.threshold = 0;
+
         emit!(ProgramInitialized {
             admin: config.admin,
             timestamp: Clock::get()?.unix_timestamp,
@@ -123,6 +139,28 @@ pub mod attestation {
         Ok(())
     }
 
+    /// Setup the verified collection mint that certificates are grouped under
+    pub fn setup_collection(
+        ctx: Context<SetupCollection>,
+        collection_mint: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        require!(
+            ctx.accounts.admin.key() == config.admin,
+            AttestationError::Unauthorized
+        );
+
+        config.collection_mint = collection_mint;
+
+        emit!(CollectionSetup {
+            collection_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
     /// Create a new attestation
     pub fn create_attestation(
         ctx: Context<CreateAttestation>,
@@ -151,12 +189,15 @@ pub mod attestation {
         attestation.is_verified = false;
         attestation.verified_by = None;
         attestation.verified_at = None;
+        attestation.verified_bitmap = 0;
         attestation.cnft_asset_id = None;
         attestation.is_compressed = false;
         attestation.compressed_account = None;
         attestation.bump = ctx.bumps.attestation;
         attestation.version = 1;
-        
+        attestation.is_revoked = false;
+        attestation.token_standard = TokenStandard::NonFungible as u8;
+
         config.total_attestations = config.total_attestations.checked_add(1)
             .ok_or(AttestationError::Overflow)?;
         
@@ -178,10 +219,11 @@ pub mod attestation {
         name: String,
         symbol: String,
         uri: String,
+        creators: Vec<MetadataCreator>,
     ) -> Result<()> {
         let attestation = &mut ctx.accounts.attestation;
         let config = &mut ctx.accounts.config;
-        
+
         require!(!config.is_paused, AttestationError::ProgramPaused);
         require!(
             ctx.accounts.creator.key() == attestation.creator,
@@ -191,10 +233,8 @@ pub mod attestation {
             attestation.cnft_asset_id.is_none(),
             AttestationError::CertificateAlreadyMinted
         );
-        require!(name.len() <= MAX_NAME_LEN, AttestationError::NameTooLong);
-        require!(symbol.len() <= MAX_SYMBOL_LEN, AttestationError::SymbolTooLong);
-        require!(uri.len() <= MAX_METADATA_URI_LEN, AttestationError::MetadataUriTooLong);
-        
+        validate_metadata_args(&name, &symbol, &uri, 0, &creators, &ctx.accounts.creator.key())?;
+
         // Build metadata for the cNFT
         let classification = if attestation.ai_probability >= 7000 {
             "AI Generated"
@@ -227,9 +267,10 @@ pub mod attestation {
             creator: attestation.creator,
             name: name.clone(),
             classification: classification.to_string(),
+            creator_count: creators.len() as u8,
             timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
@@ -239,10 +280,11 @@ pub mod attestation {
         name: String,
         symbol: String,
         uri: String,
+        creators: Vec<MetadataCreator>,
     ) -> Result<()> {
         let attestation = &mut ctx.accounts.attestation;
         let config = &ctx.accounts.config;
-        
+
         require!(!config.is_paused, AttestationError::ProgramPaused);
         require!(
             ctx.accounts.creator.key() == attestation.creator,
@@ -252,25 +294,17 @@ pub mod attestation {
             attestation.cnft_asset_id.is_none(),
             AttestationError::CertificateAlreadyMinted
         );
-        
+        validate_metadata_args(&name, &symbol, &uri, 0, &creators, &ctx.accounts.creator.key())?;
+
         // Determine classification
         let classification = if attestation.ai_probability >= 7000 {
             "AI Generated"
         } else if attestation.ai_probability <= 3000 {
-            "Human Created"  
+            "Human Created"
         } else {
             "Mixed/Uncertain"
         };
-        
-        // Create metadata creators array
-        let creators = vec![
-            MetadataCreator {
-                address: attestation.creator,
-                verified: true,
-                share: 100,
-            }
-        ];
-        
+
         // Build Bubblegum metadata args
         let metadata_args = MetadataArgs {
             name: name.clone(),
@@ -332,6 +366,268 @@ pub mod attestation {
         Ok(())
     }
 
+    /// Revoke a minted cNFT certificate via Bubblegum's burn CPI, emitting
+    /// `CertificateRevoked`. Shares its leaf-burn logic with `burn_cnft_certificate`
+    /// through `burn_cnft_leaf`; the two instructions differ only in the event they emit.
+    pub fn revoke_certificate<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeCertificate<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        let authority = ctx.accounts.creator.key();
+        let proof_accounts: Vec<AccountInfo<'info>> = ctx.remaining_accounts.to_vec();
+
+        let asset_id = burn_cnft_leaf(
+            &mut ctx.accounts.attestation,
+            &mut ctx.accounts.config,
+            authority,
+            ctx.accounts.tree_config.to_account_info(),
+            ctx.accounts.leaf_owner.to_account_info(),
+            ctx.accounts.leaf_delegate.to_account_info(),
+            ctx.accounts.merkle_tree.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            proof_accounts,
+            root,
+            data_hash,
+            creator_hash,
+            nonce,
+            index,
+        )?;
+
+        emit!(CertificateRevoked {
+            attestation: ctx.accounts.attestation.key(),
+            asset_id,
+            revoked_by: authority,
+            nonce,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Burn a minted cNFT certificate via Bubblegum's burn CPI, emitting `CertificateBurned`
+    /// for indexers/relayers that watch for that event name specifically rather than
+    /// `CertificateRevoked`. Delegates to the same `burn_cnft_leaf` logic as
+    /// `revoke_certificate` so both entry points stay byte-for-byte consistent.
+    pub fn burn_cnft_certificate<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnCnftCertificate<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        let authority = ctx.accounts.creator.key();
+        let proof_accounts: Vec<AccountInfo<'info>> = ctx.remaining_accounts.to_vec();
+
+        let asset_id = burn_cnft_leaf(
+            &mut ctx.accounts.attestation,
+            &mut ctx.accounts.config,
+            authority,
+            ctx.accounts.tree_config.to_account_info(),
+            ctx.accounts.leaf_owner.to_account_info(),
+            ctx.accounts.leaf_delegate.to_account_info(),
+            ctx.accounts.merkle_tree.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            proof_accounts,
+            root,
+            data_hash,
+            creator_hash,
+            nonce,
+            index,
+        )?;
+
+        emit!(CertificateBurned {
+            attestation: ctx.accounts.attestation.key(),
+            asset_id,
+            burned_by: authority,
+            nonce,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Mint a cNFT certificate as a verified member of the program's collection
+    pub fn mint_cnft_to_collection<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintCnftToCollection<'info>>,
+        name: String,
+        symbol: String,
+        uri: String,
+        creators: Vec<MetadataCreator>,
+    ) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+        let config = &mut ctx.accounts.config;
+
+        require!(!config.is_paused, AttestationError::ProgramPaused);
+        require!(
+            ctx.accounts.creator.key() == attestation.creator,
+            AttestationError::Unauthorized
+        );
+        require!(
+            attestation.cnft_asset_id.is_none(),
+            AttestationError::CertificateAlreadyMinted
+        );
+        require!(
+            config.collection_mint == ctx.accounts.collection_mint.key(),
+            AttestationError::InvalidCollection
+        );
+        validate_metadata_args(&name, &symbol, &uri, 0, &creators, &ctx.accounts.creator.key())?;
+
+        let classification = if attestation.ai_probability >= 7000 {
+            "AI Generated"
+        } else if attestation.ai_probability <= 3000 {
+            "Human Created"
+        } else {
+            "Mixed/Uncertain"
+        };
+
+        let metadata_args = MetadataArgs {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            primary_sale_happened: true,
+            is_mutable: false,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection: Some(Collection {
+                verified: true,
+                key: ctx.accounts.collection_mint.key(),
+            }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators,
+        };
+
+        // Prepare seeds for the config PDA, which signs as collection authority
+        let config_seeds = &[CONFIG_SEED, &[config.bump]];
+        let signer_seeds = &[&config_seeds[..]];
+
+        // CPI to Bubblegum's mint_to_collection_v1, with collection authority PDA signing
+        let cpi_accounts = MintToCollectionV1Cpi {
+            tree_config: ctx.accounts.tree_config.to_account_info(),
+            leaf_owner: ctx.accounts.creator.to_account_info(),
+            leaf_delegate: ctx.accounts.creator.to_account_info(),
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            payer: ctx.accounts.creator.to_account_info(),
+            tree_creator_or_delegate: ctx.accounts.tree_authority.to_account_info(),
+            collection_authority: ctx.accounts.config.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            collection_authority_record: ctx.accounts.collection_authority_record.to_account_info(),
+            bubblegum_signer: ctx.accounts.bubblegum_signer.to_account_info(),
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: ctx.accounts.compression_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+
+        // Note: In production, you'd call:
+        // bubblegum::cpi::mint_to_collection_v1(cpi_ctx, metadata_args)?;
+        let _ = (cpi_accounts, signer_seeds);
+
+        let nonce = config.total_certificates;
+        let asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), nonce);
+
+        attestation.cnft_asset_id = Some(asset_id);
+        config.total_certificates = config.total_certificates.checked_add(1)
+            .ok_or(AttestationError::Overflow)?;
+
+        emit!(CnftCertificateMinted {
+            attestation: ctx.accounts.attestation.key(),
+            merkle_tree: ctx.accounts.merkle_tree.key(),
+            asset_id,
+            leaf_index: nonce,
+            creator: attestation.creator,
+            name,
+            symbol,
+            uri,
+            classification: classification.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        emit!(CnftMintedToCollection {
+            attestation: ctx.accounts.attestation.key(),
+            asset_id,
+            collection_mint: ctx.accounts.collection_mint.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Mint a soulbound programmable-NFT certificate that cannot be transferred
+    /// once minted, permanently binding it to the creator who produced the
+    /// AI-detection result.
+    pub fn create_pnft_certificate<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePnftCertificate<'info>>,
+        name: String,
+        symbol: String,
+        uri: String,
+        creators: Vec<MetadataCreator>,
+    ) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+        let config = &mut ctx.accounts.config;
+
+        require!(!config.is_paused, AttestationError::ProgramPaused);
+        require!(
+            ctx.accounts.creator.key() == attestation.creator,
+            AttestationError::Unauthorized
+        );
+        require!(
+            attestation.cnft_asset_id.is_none(),
+            AttestationError::CertificateAlreadyMinted
+        );
+        validate_metadata_args(&name, &symbol, &uri, 0, &creators, &ctx.accounts.creator.key())?;
+
+        let config_seeds = &[CONFIG_SEED, &[config.bump]];
+        let signer_seeds = &[&config_seeds[..]];
+
+        // CPI into Metaplex Token Metadata's programmable-asset mint path, with the
+        // config PDA as update authority signing a "no transfer" rule-set enforcement.
+        let cpi_accounts = CreatePnftCpi {
+            metadata: ctx.accounts.metadata.to_account_info(),
+            master_edition: ctx.accounts.master_edition.to_account_info(),
+            token_record: ctx.accounts.token_record.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            update_authority: config.to_account_info(),
+            payer: ctx.accounts.creator.to_account_info(),
+            authorization_rules: ctx.accounts.authorization_rules.to_account_info(),
+            authorization_rules_program: ctx.accounts.authorization_rules_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+
+        // Note: In production, you'd call:
+        // token_metadata::cpi::create(cpi_ctx, CreateArgs::V1 { .. })?;
+        let _ = (cpi_accounts, signer_seeds);
+
+        let asset_id = ctx.accounts.mint.key();
+        attestation.cnft_asset_id = Some(asset_id);
+        attestation.token_standard = TokenStandard::ProgrammableNonFungible as u8;
+        config.total_certificates = config.total_certificates.checked_add(1)
+            .ok_or(AttestationError::Overflow)?;
+
+        emit!(PnftCertificateCreated {
+            attestation: ctx.accounts.attestation.key(),
+            mint: asset_id,
+            creator: attestation.creator,
+            name,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
     /// Compress attestation data using Light Protocol
     pub fn compress_attestation(
         ctx: Context<CompressAttestation>,
@@ -346,12 +642,12 @@ pub mod attestation {
             !attestation.is_compressed,
             AttestationError::AlreadyCompressed
         );
-        
+
         // Prepare compressed data structure
         let compressed_data = CompressedAttestationData {
             content_hash: attestation.content_hash,
             ai_probability: attestation.ai_probability,
-            content_type_hash: hash_string(&attestation.content_type),
+            content_type_hash: model_key(&attestation.content_type),
             creator: attestation.creator,
             created_at: attestation.created_at,
             is_verified: attestation.is_verified,
@@ -397,12 +693,12 @@ pub mod attestation {
         
         require!(!config.is_paused, AttestationError::ProgramPaused);
         require!(ai_probability <= 10000, AttestationError::InvalidProbability);
-        
+
         // Prepare compressed attestation data
         let compressed_data = CompressedAttestationData {
             content_hash,
             ai_probability,
-            content_type_hash: hash_string(&content_type),
+            content_type_hash: model_key(&content_type),
             creator: ctx.accounts.creator.key(),
             created_at: Clock::get()?.unix_timestamp,
             is_verified: false,
@@ -436,27 +732,166 @@ pub mod attestation {
         Ok(())
     }
 
-    /// Verify an attestation (admin only)
-    pub fn verify_attestation(ctx: Context<VerifyAttestation>) -> Result<()> {
-        let attestation = &mut ctx.accounts.attestation;
-        let config = &ctx.accounts.config;
-        
+    /// Publish a Wormhole-compatible message so the attestation can be consumed on other chains,
+    /// emitting `AttestationVaaPublished`. Shares its payload-build/CPI logic with
+    /// `broadcast_attestation` through `post_attestation_vaa`; the two instructions differ
+    /// only in the event they emit.
+    pub fn publish_attestation_vaa(ctx: Context<PublishAttestationVaa>) -> Result<()> {
+        let sequence = post_attestation_vaa(
+            &ctx.accounts.attestation,
+            &ctx.accounts.config,
+            &ctx.accounts.wormhole_program,
+            &ctx.accounts.bridge_config,
+            &ctx.accounts.message,
+            &ctx.accounts.emitter,
+            &ctx.accounts.sequence,
+            &ctx.accounts.payer,
+            &ctx.accounts.fee_collector,
+            &ctx.accounts.clock,
+            &ctx.accounts.system_program,
+            ctx.bumps.emitter,
+        )?;
+
+        emit!(AttestationVaaPublished {
+            attestation: ctx.accounts.attestation.key(),
+            emitter: ctx.accounts.emitter.key(),
+            sequence,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Broadcast an attestation over Wormhole, emitting `AttestationBroadcast` for
+    /// indexers/relayers that watch for that event name specifically rather than
+    /// `AttestationVaaPublished`. Delegates to the same `post_attestation_vaa` logic as
+    /// `publish_attestation_vaa` so both entry points post byte-for-byte the same payload.
+    pub fn broadcast_attestation(ctx: Context<BroadcastAttestation>) -> Result<()> {
+        let sequence = post_attestation_vaa(
+            &ctx.accounts.attestation,
+            &ctx.accounts.config,
+            &ctx.accounts.wormhole_program,
+            &ctx.accounts.bridge_config,
+            &ctx.accounts.message,
+            &ctx.accounts.emitter,
+            &ctx.accounts.sequence,
+            &ctx.accounts.payer,
+            &ctx.accounts.fee_collector,
+            &ctx.accounts.clock,
+            &ctx.accounts.system_program,
+            ctx.bumps.emitter,
+        )?;
+
+        emit!(AttestationBroadcast {
+            attestation: ctx.accounts.attestation.key(),
+            emitter: ctx.accounts.emitter.key(),
+            sequence,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Register the verifier committee and the quorum threshold (admin only).
+    ///
+    /// A verifier's index into `config.verifiers` is what each bit of an attestation's
+    /// `verified_bitmap` refers to, so replacing the committee while any attestation has
+    /// a partial (non-zero, not-yet-quorate) bitmap would silently repoint those bits at
+    /// the wrong verifiers. Rejected until every such attestation has reached quorum via
+    /// `attest_vote`, which is what `config.pending_votes` tracks.
+    pub fn setup_verifiers(
+        ctx: Context<SetupVerifiers>,
+        verifiers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
         require!(
-            ctx.accounts.authority.key() == config.admin,
+            ctx.accounts.admin.key() == config.admin,
             AttestationError::Unauthorized
         );
+        require!(
+            verifiers.len() <= MAX_VERIFIERS,
+            AttestationError::TooManyVerifiers
+        );
+        require!(
+            threshold as usize > 0 && threshold as usize <= verifiers.len(),
+            AttestationError::InvalidThreshold
+        );
+        require!(
+            config.pending_votes == 0,
+            AttestationError::PendingVotesOutstanding
+        );
+
+        config.verifier_count = verifiers.len() as u8;
+        config.threshold = threshold;
+        config.verifiers = [Pubkey::default(); MAX_VERIFIERS];
+        for (i, verifier) in verifiers.iter().enumerate() {
+            config.verifiers[i] = *verifier;
+        }
+
+        emit!(VerifierCommitteeUpdated {
+            verifier_count: config.verifier_count,
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Cast a verifier's attestation vote, reaching quorum once `threshold` distinct
+    /// verifiers have attested
+    pub fn attest_vote(ctx: Context<AttestVote>) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+        let config = &mut ctx.accounts.config;
+
         require!(!attestation.is_verified, AttestationError::AlreadyVerified);
-        
-        attestation.is_verified = true;
-        attestation.verified_by = Some(ctx.accounts.authority.key());
-        attestation.verified_at = Some(Clock::get()?.unix_timestamp);
-        
-        emit!(AttestationVerified {
+
+        let verifier_index = config.verifiers[..config.verifier_count as usize]
+            .iter()
+            .position(|v| *v == ctx.accounts.verifier.key())
+            .ok_or(AttestationError::NotInCommittee)?;
+
+        let voter_bit = 1u32 << verifier_index;
+        let candidate_bitmap = attestation.verified_bitmap | voter_bit;
+
+        // "Is known subset" check: if OR-ing in this voter's bit doesn't change
+        // the bitmap, this voter already attested and the vote is redundant.
+        require!(
+            candidate_bitmap != attestation.verified_bitmap,
+            AttestationError::RedundantAttestation
+        );
+
+        // The first vote opens an outstanding partial-quorum window that setup_verifiers
+        // must not be allowed to corrupt; track it in config until quorum is reached.
+        if attestation.verified_bitmap == 0 {
+            config.pending_votes = config.pending_votes.checked_add(1)
+                .ok_or(AttestationError::Overflow)?;
+        }
+
+        attestation.verified_bitmap = candidate_bitmap;
+
+        if attestation.verified_bitmap.count_ones() >= config.threshold as u32 {
+            attestation.is_verified = true;
+            attestation.verified_by = Some(ctx.accounts.verifier.key());
+            attestation.verified_at = Some(Clock::get()?.unix_timestamp);
+            config.pending_votes = config.pending_votes.checked_sub(1)
+                .ok_or(AttestationError::Overflow)?;
+
+            emit!(QuorumReached {
+                attestation: ctx.accounts.attestation.key(),
+                verified_bitmap: attestation.verified_bitmap,
+                timestamp: attestation.verified_at.unwrap(),
+            });
+        }
+
+        emit!(AttestationVoteCast {
             attestation: ctx.accounts.attestation.key(),
-            verified_by: ctx.accounts.authority.key(),
-            timestamp: attestation.verified_at.unwrap(),
+            verifier: ctx.accounts.verifier.key(),
+            verified_bitmap: attestation.verified_bitmap,
+            timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
@@ -563,11 +998,17 @@ pub struct ProgramConfig {
     pub merkle_tree: Pubkey,        // 32 bytes
     pub tree_authority_bump: u8,    // 1 byte
     pub bump: u8,                   // 1 byte
-    pub _reserved: [u8; 64],        // 64 bytes for future use
+    pub collection_mint: Pubkey,    // 32 bytes
+    pub verifiers: [Pubkey; MAX_VERIFIERS], // 32 * 32 bytes
+    pub verifier_count: u8,         // 1 byte
+    pub threshold: u8,              // 1 byte
+    pub pending_votes: u32,         // 4 bytes: attestations with an outstanding partial vote
+    pub _reserved: [u8; 60],        // 60 bytes for future use
 }
 
 impl ProgramConfig {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 1 + 32 + 1 + 1 + 64;
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1 + 32 + 1 + 1 + 32
+        + (32 * MAX_VERIFIERS) + 1 + 1 + 4 + 60;
 }
 
 #[account]
@@ -582,17 +1023,20 @@ pub struct Attestation {
     pub is_verified: bool,                      // 1 byte
     pub verified_by: Option<Pubkey>,            // 1 + 32 bytes
     pub verified_at: Option<i64>,               // 1 + 8 bytes
+    pub verified_bitmap: u32,                   // 4 bytes
     pub cnft_asset_id: Option<Pubkey>,          // 1 + 32 bytes
     pub is_compressed: bool,                    // 1 byte
     pub compressed_account: Option<Pubkey>,     // 1 + 32 bytes
     pub bump: u8,                               // 1 byte
     pub version: u8,                            // 1 byte
+    pub is_revoked: bool,                       // 1 byte
+    pub token_standard: u8,                     // 1 byte (TokenStandard discriminant)
 }
 
 impl Attestation {
-    pub const LEN: usize = 8 + 32 + 2 + (4 + MAX_CONTENT_TYPE_LEN) + 
-        (4 + MAX_DETECTION_MODEL_LEN) + (4 + MAX_METADATA_URI_LEN) + 
-        32 + 8 + 1 + 33 + 9 + 33 + 1 + 33 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 2 + (4 + MAX_CONTENT_TYPE_LEN) +
+        (4 + MAX_DETECTION_MODEL_LEN) + (4 + MAX_METADATA_URI_LEN) +
+        32 + 8 + 1 + 33 + 9 + 4 + 33 + 1 + 33 + 1 + 1 + 1 + 1;
 }
 
 /// Compressed attestation data structure for Light Protocol
@@ -644,6 +1088,19 @@ pub struct SetupMerkleTree<'info> {
     pub merkle_tree: UncheckedAccount<'info>,
 }
 
+#[derive(Accounts)]
+pub struct SetupCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
+
 #[derive(Accounts)]
 #[instruction(content_hash: [u8; 32])]
 pub struct CreateAttestation<'info> {
@@ -731,21 +1188,163 @@ pub struct MintCnftCertificate<'info> {
 }
 
 #[derive(Accounts)]
-pub struct CompressAttestation<'info> {
+pub struct MintCnftToCollection<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
-    
+
     #[account(
         mut,
         constraint = attestation.creator == creator.key() @ AttestationError::Unauthorized
     )]
     pub attestation: Account<'info, Attestation>,
-    
-    /// CHECK: Light Protocol system program
-    pub light_system_program: UncheckedAccount<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Tree config account (validated by Bubblegum)
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Merkle tree account
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Tree authority PDA
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Collection mint, must match config.collection_mint
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Collection metadata account
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition account
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Collection authority record (config PDA's delegation)
+    pub collection_authority_record: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum's collection verification signer PDA
+    pub bubblegum_signer: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop program for logging
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum program
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeCertificate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Tree config account (validated by Bubblegum)
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Leaf owner (the certificate's current owner)
+    pub leaf_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Leaf delegate
+    pub leaf_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: Merkle tree account
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop program for logging
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum program
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnCnftCertificate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Tree config account (validated by Bubblegum)
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Leaf owner (the certificate's current owner)
+    pub leaf_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Leaf delegate
+    pub leaf_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: Merkle tree account
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop program for logging
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum program
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CompressAttestation<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = attestation.creator == creator.key() @ AttestationError::Unauthorized
+    )]
+    pub attestation: Account<'info, Attestation>,
+    
+    /// CHECK: Light Protocol system program
+    pub light_system_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
 
 #[derive(Accounts)]
 pub struct CreateCompressedAttestation<'info> {
@@ -781,17 +1380,166 @@ pub struct CreateCompressedAttestation<'info> {
 }
 
 #[derive(Accounts)]
-pub struct VerifyAttestation<'info> {
-    pub authority: Signer<'info>,
-    
+pub struct CreatePnftCertificate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
     #[account(
         mut,
+        constraint = attestation.creator == creator.key() @ AttestationError::Unauthorized
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Freshly created mint for the soulbound certificate
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token Metadata metadata account for the mint
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Token Metadata master edition account for the mint
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Token record account tracking this certificate's programmable state
+    #[account(mut)]
+    pub token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization rule set forbidding transfer (soulbound enforcement)
+    pub authorization_rules: UncheckedAccount<'info>,
+
+    /// CHECK: Token Authorization Rules program
+    pub authorization_rules_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PublishAttestationVaa<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
         seeds = [ATTESTATION_SEED, attestation.content_hash.as_ref()],
         bump = attestation.bump
     )]
     pub attestation: Account<'info, Attestation>,
-    
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Wormhole core bridge config account
+    #[account(mut)]
+    pub bridge_config: UncheckedAccount<'info>,
+
+    /// Emitter PDA derived from this program, signs the post_message CPI
+    /// CHECK: validated via seeds
+    #[account(seeds = [EMITTER_SEED], bump)]
+    pub emitter: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole sequence tracker for this emitter
+    #[account(mut)]
+    pub sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole fee collector
+    #[account(mut)]
+    pub fee_collector: UncheckedAccount<'info>,
+
+    /// The posted-message account; must co-sign the post_message CPI
+    #[account(mut)]
+    pub message: Signer<'info>,
+
+    /// CHECK: Wormhole core bridge program
+    pub wormhole_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BroadcastAttestation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTATION_SEED, attestation.content_hash.as_ref()],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Wormhole core bridge config account
+    #[account(mut)]
+    pub bridge_config: UncheckedAccount<'info>,
+
+    /// Emitter PDA derived from this program, signs the post_message CPI
+    /// CHECK: validated via seeds
+    #[account(seeds = [EMITTER_SEED], bump)]
+    pub emitter: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole sequence tracker for this emitter
+    #[account(mut)]
+    pub sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole fee collector
+    #[account(mut)]
+    pub fee_collector: UncheckedAccount<'info>,
+
+    /// The posted-message account; must co-sign the post_message CPI
+    #[account(mut)]
+    pub message: Signer<'info>,
+
+    /// CHECK: Wormhole core bridge program
+    pub wormhole_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetupVerifiers<'info> {
+    pub admin: Signer<'info>,
+
     #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
+
+#[derive(Accounts)]
+pub struct AttestVote<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ATTESTATION_SEED, attestation.content_hash.as_ref()],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        mut,
         seeds = [CONFIG_SEED],
         bump = config.bump
     )]
@@ -879,6 +1627,7 @@ pub enum TokenStandard {
     FungibleAsset,
     Fungible,
     NonFungibleEdition,
+    ProgrammableNonFungible,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -920,6 +1669,52 @@ pub struct MintV1Cpi<'info> {
     pub system_program: AccountInfo<'info>,
 }
 
+/// Placeholder for Bubblegum's mint_to_collection_v1 CPI accounts
+pub struct MintToCollectionV1Cpi<'info> {
+    pub tree_config: AccountInfo<'info>,
+    pub leaf_owner: AccountInfo<'info>,
+    pub leaf_delegate: AccountInfo<'info>,
+    pub merkle_tree: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub tree_creator_or_delegate: AccountInfo<'info>,
+    pub collection_authority: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+    pub collection_authority_record: AccountInfo<'info>,
+    pub bubblegum_signer: AccountInfo<'info>,
+    pub log_wrapper: AccountInfo<'info>,
+    pub compression_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Placeholder for Metaplex Token Metadata's programmable-asset create CPI accounts
+pub struct CreatePnftCpi<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub token_record: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub authorization_rules: AccountInfo<'info>,
+    pub authorization_rules_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Placeholder for Bubblegum's burn CPI accounts (BurnCpiBuilder-style)
+pub struct BurnCpi<'info> {
+    pub tree_config: AccountInfo<'info>,
+    pub leaf_owner: AccountInfo<'info>,
+    pub leaf_delegate: AccountInfo<'info>,
+    pub merkle_tree: AccountInfo<'info>,
+    pub log_wrapper: AccountInfo<'info>,
+    pub compression_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub proof: Vec<AccountInfo<'info>>,
+}
+
 // ============================================================
 // EVENTS
 // ============================================================
@@ -938,6 +1733,20 @@ pub struct MerkleTreeSetup {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct CollectionSetup {
+    pub collection_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CnftMintedToCollection {
+    pub attestation: Pubkey,
+    pub asset_id: Pubkey,
+    pub collection_mint: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AttestationCreated {
     pub content_hash: [u8; 32],
@@ -955,6 +1764,7 @@ pub struct CertificateMinted {
     pub creator: Pubkey,
     pub name: String,
     pub classification: String,
+    pub creator_count: u8,
     pub timestamp: i64,
 }
 
@@ -972,6 +1782,24 @@ pub struct CnftCertificateMinted {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct CertificateRevoked {
+    pub attestation: Pubkey,
+    pub asset_id: Pubkey,
+    pub revoked_by: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateBurned {
+    pub attestation: Pubkey,
+    pub asset_id: Pubkey,
+    pub burned_by: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AttestationCompressed {
     pub attestation: Pubkey,
@@ -992,9 +1820,49 @@ pub struct CompressedAttestationCreated {
 }
 
 #[event]
-pub struct AttestationVerified {
+pub struct PnftCertificateCreated {
     pub attestation: Pubkey,
-    pub verified_by: Pubkey,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationVaaPublished {
+    pub attestation: Pubkey,
+    pub emitter: Pubkey,
+    pub sequence: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationBroadcast {
+    pub attestation: Pubkey,
+    pub emitter: Pubkey,
+    pub sequence: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VerifierCommitteeUpdated {
+    pub verifier_count: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationVoteCast {
+    pub attestation: Pubkey,
+    pub verifier: Pubkey,
+    pub verified_bitmap: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QuorumReached {
+    pub attestation: Pubkey,
+    pub verified_bitmap: u32,
     pub timestamp: i64,
 }
 
@@ -1074,20 +1942,141 @@ pub enum AttestationError {
     
     #[msg("Compression failed")]
     CompressionFailed,
+
+    #[msg("Certificate already revoked")]
+    CertificateAlreadyRevoked,
+
+    #[msg("Certificate not minted")]
+    CertificateNotMinted,
+
+    #[msg("Collection mint does not match the configured attestation collection")]
+    InvalidCollection,
+
+    #[msg("Too many creators (max 5)")]
+    TooManyCreators,
+
+    #[msg("Duplicate creator address")]
+    DuplicateCreatorAddress,
+
+    #[msg("Creator shares must sum to exactly 100")]
+    ShareTotalMustBe100,
+
+    #[msg("Only a transaction signer can be marked as a verified creator")]
+    CreatorNotSigner,
+
+    #[msg("Too many verifiers (max 32)")]
+    TooManyVerifiers,
+
+    #[msg("Threshold must be between 1 and the verifier count")]
+    InvalidThreshold,
+
+    #[msg("Cannot replace the verifier committee while attestations have an outstanding partial vote")]
+    PendingVotesOutstanding,
+
+    #[msg("Signer is not a registered verifier")]
+    NotInCommittee,
+
+    #[msg("Verifier has already attested to this attestation")]
+    RedundantAttestation,
+
+    #[msg("Seller fee basis points must be 10000 or less")]
+    InvalidSellerFeeBasisPoints,
 }
 
 // ============================================================
 // HELPER FUNCTIONS
 // ============================================================
 
-/// Hash a string to 8 bytes for compact storage
-fn hash_string(s: &str) -> [u8; 8] {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-    
-    let mut hasher = DefaultHasher::new();
-    s.hash(&mut hasher);
-    hasher.finish().to_le_bytes()
+/// Validate mint metadata args exactly as Metaplex's `assert_data_valid` does:
+/// bounded name/symbol/uri lengths, a sane fee, a bounded creator list with no
+/// duplicate addresses, shares summing to 100, and only the transaction signer
+/// may be marked as a verified creator. Wired into every mint path so malformed
+/// metadata is rejected before the expensive CPI.
+fn validate_metadata_args(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    seller_fee_basis_points: u16,
+    creators: &[MetadataCreator],
+    signer: &Pubkey,
+) -> Result<()> {
+    require!(name.len() <= MAX_NAME_LEN, AttestationError::NameTooLong);
+    require!(symbol.len() <= MAX_SYMBOL_LEN, AttestationError::SymbolTooLong);
+    require!(uri.len() <= MAX_METADATA_URI_LEN, AttestationError::MetadataUriTooLong);
+    require!(seller_fee_basis_points <= 10000, AttestationError::InvalidSellerFeeBasisPoints);
+    require!(
+        creators.len() <= MAX_CREATOR_LIMIT,
+        AttestationError::TooManyCreators
+    );
+
+    for (i, creator) in creators.iter().enumerate() {
+        require!(
+            !creators[..i].iter().any(|c| c.address == creator.address),
+            AttestationError::DuplicateCreatorAddress
+        );
+        if creator.verified {
+            require!(creator.address == *signer, AttestationError::CreatorNotSigner);
+        }
+    }
+
+    let share_total: u16 = creators.iter().map(|c| c.share as u16).sum();
+    require!(share_total == 100, AttestationError::ShareTotalMustBe100);
+
+    Ok(())
+}
+
+/// Compute a stable, collision-resistant compact key for a model/content-type string.
+/// Uses the first 8 bytes of its keccak-256 digest, reproducible off-chain for
+/// compressed-attestation reconstruction and proof verification.
+///
+/// Note: this program does not persist a registry of previously-seen
+/// `detection_model`/`content_type` strings, so there is no on-chain state to
+/// check a freshly submitted string against for collisions (a future instruction
+/// maintaining such a registry, e.g. a PDA per compact key storing the original
+/// string, would need its own dedicated error variant at that point).
+fn model_key(s: &str) -> [u8; 8] {
+    let hash = anchor_lang::solana_program::keccak::hashv(&[s.as_bytes()]);
+    let mut key = [0u8; 8];
+    key.copy_from_slice(&hash.0[..8]);
+    key
+}
+
+/// Build the instruction for Wormhole core bridge's `post_message`, following the
+/// account layout used by `attest_token`-style CPIs.
+#[allow(clippy::too_many_arguments)]
+fn wormhole_post_message_ix(
+    wormhole_program: &Pubkey,
+    bridge_config: &Pubkey,
+    message: &Pubkey,
+    emitter: &Pubkey,
+    sequence: &Pubkey,
+    payer: &Pubkey,
+    fee_collector: &Pubkey,
+    payload: &[u8],
+) -> anchor_lang::solana_program::instruction::Instruction {
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+    // post_message(nonce: u32, payload: Vec<u8>, consistency_level: u8)
+    let mut data = vec![1u8]; // post_message instruction discriminant
+    data.extend_from_slice(&0u32.to_le_bytes()); // nonce
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(payload);
+    data.push(0); // consistency_level: Confirmed
+
+    Instruction {
+        program_id: *wormhole_program,
+        accounts: vec![
+            AccountMeta::new(*bridge_config, false),
+            AccountMeta::new(*message, true),
+            AccountMeta::new_readonly(*emitter, true),
+            AccountMeta::new(*sequence, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*fee_collector, false),
+            AccountMeta::new_readonly(anchor_lang::solana_program::sysvar::clock::ID, false),
+            AccountMeta::new_readonly(anchor_lang::solana_program::system_program::ID, false),
+        ],
+        data,
+    }
 }
 
 /// Compute asset ID for a compressed NFT
@@ -1101,3 +2090,154 @@ fn get_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
         &external_programs::bubblegum::ID,
     ).0
 }
+
+/// Shared logic behind `publish_attestation_vaa` and `broadcast_attestation`: builds the
+/// compact cross-chain payload and CPIs into Wormhole's `post_message`, signed by the
+/// emitter PDA. Returns the assigned sequence number. The two public instructions differ
+/// only in which event they emit.
+#[allow(clippy::too_many_arguments)]
+fn post_attestation_vaa<'info>(
+    attestation: &Account<'info, Attestation>,
+    config: &Account<'info, ProgramConfig>,
+    wormhole_program: &UncheckedAccount<'info>,
+    bridge_config: &UncheckedAccount<'info>,
+    message: &Signer<'info>,
+    emitter: &UncheckedAccount<'info>,
+    sequence: &UncheckedAccount<'info>,
+    payer: &Signer<'info>,
+    fee_collector: &UncheckedAccount<'info>,
+    clock: &Sysvar<'info, Clock>,
+    system_program: &Program<'info, System>,
+    emitter_bump: u8,
+) -> Result<u64> {
+    let classification: u8 = if attestation.ai_probability >= 7000 {
+        0 // AI Generated
+    } else if attestation.ai_probability <= 3000 {
+        1 // Human Created
+    } else {
+        2 // Mixed/Uncertain
+    };
+
+    // Compact payload: content hash + probability + classification + verified flag
+    // + detection model digest + creator + timestamp
+    let mut payload = Vec::with_capacity(32 + 2 + 1 + 1 + 8 + 32 + 8);
+    payload.extend_from_slice(&attestation.content_hash);
+    payload.extend_from_slice(&attestation.ai_probability.to_le_bytes());
+    payload.push(classification);
+    payload.push(attestation.is_verified as u8);
+    payload.extend_from_slice(&model_key(&attestation.detection_model));
+    payload.extend_from_slice(attestation.creator.as_ref());
+    payload.extend_from_slice(&Clock::get()?.unix_timestamp.to_le_bytes());
+
+    let emitter_seeds = &[EMITTER_SEED, &[emitter_bump]];
+    let signer_seeds = &[&emitter_seeds[..]];
+
+    // The core bridge gives the message the sequence tracker's current value and then
+    // increments it; a tracker that does not exist yet starts at 0.
+    let assigned_sequence = {
+        let data = sequence.try_borrow_data()?;
+        if data.len() >= 8 {
+            u64::from_le_bytes(data[..8].try_into().unwrap())
+        } else {
+            0
+        }
+    };
+
+    // CPI to the Wormhole core bridge's post_message, signed by our emitter PDA
+    let ix = wormhole_post_message_ix(
+        &wormhole_program.key(),
+        &bridge_config.key(),
+        &message.key(),
+        &emitter.key(),
+        &sequence.key(),
+        &payer.key(),
+        &fee_collector.key(),
+        &payload,
+    );
+
+    invoke_signed(
+        &ix,
+        &[
+            bridge_config.to_account_info(),
+            message.to_account_info(),
+            emitter.to_account_info(),
+            sequence.to_account_info(),
+            payer.to_account_info(),
+            fee_collector.to_account_info(),
+            clock.to_account_info(),
+            system_program.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(assigned_sequence)
+}
+
+/// Shared burn logic behind `revoke_certificate` and `burn_cnft_certificate`: validates the
+/// caller, checks that the leaf nonce recomputes to the stored asset id (the proof accounts
+/// and root are not verified here), builds the accounts for Bubblegum's burn CPI (the call
+/// itself is not made), and clears `cnft_asset_id`.
+/// Returns the burned asset id. The two public instructions differ only in which event they emit.
+#[allow(clippy::too_many_arguments)]
+fn burn_cnft_leaf<'info>(
+    attestation: &mut Account<'info, Attestation>,
+    config: &mut Account<'info, ProgramConfig>,
+    authority: Pubkey,
+    tree_config: AccountInfo<'info>,
+    leaf_owner: AccountInfo<'info>,
+    leaf_delegate: AccountInfo<'info>,
+    merkle_tree: AccountInfo<'info>,
+    log_wrapper: AccountInfo<'info>,
+    compression_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    proof_accounts: Vec<AccountInfo<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Result<Pubkey> {
+    require!(
+        authority == attestation.creator || authority == config.admin,
+        AttestationError::Unauthorized
+    );
+    require!(!attestation.is_revoked, AttestationError::CertificateAlreadyRevoked);
+    require!(
+        attestation.cnft_asset_id.is_some(),
+        AttestationError::CertificateNotMinted
+    );
+
+    let merkle_tree_key = merkle_tree.key();
+    let config_seeds = &[CONFIG_SEED, &[config.bump]];
+    let signer_seeds = &[&config_seeds[..]];
+
+    // CPI to Bubblegum's burn instruction (BurnCpiBuilder-style)
+    let cpi_accounts = BurnCpi {
+        tree_config,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        log_wrapper,
+        compression_program,
+        system_program,
+        proof: proof_accounts,
+    };
+
+    // Note: In production, you'd call:
+    // bubblegum::cpi::burn(cpi_ctx, root, data_hash, creator_hash, nonce, index)?;
+    let _ = (cpi_accounts, signer_seeds, root, data_hash, creator_hash, index);
+
+    // The leaf's nonce must recompute to the asset id stored on the attestation
+    let computed_asset_id = get_asset_id(&merkle_tree_key, nonce);
+    require!(
+        attestation.cnft_asset_id == Some(computed_asset_id),
+        AttestationError::InvalidMerkleTree
+    );
+
+    let asset_id = attestation.cnft_asset_id.take().unwrap();
+    attestation.is_revoked = true;
+    config.total_certificates = config.total_certificates.checked_sub(1)
+        .ok_or(AttestationError::Overflow)?;
+
+    Ok(asset_id)
+}